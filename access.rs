@@ -0,0 +1,148 @@
+use ink::prelude::vec;
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+use ink::storage::Mapping;
+
+use crate::errors::PSP22Error;
+
+/// Identifies a role. Plain `u32` rather than an enum so a deployment can mint
+/// its own custom roles beyond the well-known ones below.
+pub type RoleId = u32;
+
+/// Can grant/revoke any role whose admin role hasn't been reassigned, and is
+/// the default admin for every role.
+pub const ADMIN: RoleId = 0;
+/// Allowed to call `Token::mint`.
+pub const MINTER: RoleId = 1;
+/// Allowed to call `Token::burn`.
+pub const BURNER: RoleId = 2;
+
+/// Events emitted by `AccessControl`'s methods, to be re-emitted by the
+/// contract that embeds it.
+#[derive(Debug)]
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+pub enum AccessControlEvent {
+    RoleGranted {
+        role: RoleId,
+        account: AccountId,
+        sender: AccountId,
+    },
+    RoleRevoked {
+        role: RoleId,
+        account: AccountId,
+        sender: AccountId,
+    },
+    RoleAdminChanged {
+        role: RoleId,
+        previous_admin_role: RoleId,
+        new_admin_role: RoleId,
+    },
+}
+
+/// Storage and logic for role-based access control, meant to be embedded as a
+/// field in a contract's storage struct. Mirrors OpenZeppelin's
+/// `AccessControl`: every role has an admin role (defaulting to `ADMIN`)
+/// whose holders may grant or revoke it, and any account may renounce a role
+/// it holds itself.
+#[derive(Default)]
+#[ink::storage_item]
+pub struct AccessControl {
+    roles: Mapping<(RoleId, AccountId), ()>,
+    role_admins: Mapping<RoleId, RoleId>,
+}
+
+impl AccessControl {
+    /// Seeds `admin` with the `ADMIN` role.
+    pub fn new(admin: AccountId) -> Self {
+        let mut access: Self = Default::default();
+        access.roles.insert((ADMIN, admin), &());
+        access
+    }
+
+    pub fn has_role(&self, role: RoleId, account: AccountId) -> bool {
+        self.roles.contains((role, account))
+    }
+
+    /// The role allowed to grant/revoke `role`; `ADMIN` unless reassigned.
+    fn admin_of(&self, role: RoleId) -> RoleId {
+        self.role_admins.get(role).unwrap_or(ADMIN)
+    }
+
+    /// Reassigns the role allowed to grant/revoke `role` to `new_admin_role`.
+    /// Only an existing holder of `role`'s current admin role may do this.
+    pub fn set_role_admin(
+        &mut self,
+        role: RoleId,
+        new_admin_role: RoleId,
+        caller: AccountId,
+    ) -> Result<Vec<AccessControlEvent>, PSP22Error> {
+        let previous_admin_role = self.admin_of(role);
+        if !self.has_role(previous_admin_role, caller) {
+            return Err(PSP22Error::MissingRole);
+        }
+        if previous_admin_role == new_admin_role {
+            return Ok(vec![]);
+        }
+        self.role_admins.insert(role, &new_admin_role);
+        Ok(vec![AccessControlEvent::RoleAdminChanged {
+            role,
+            previous_admin_role,
+            new_admin_role,
+        }])
+    }
+
+    pub fn grant_role(
+        &mut self,
+        role: RoleId,
+        account: AccountId,
+        caller: AccountId,
+    ) -> Result<Vec<AccessControlEvent>, PSP22Error> {
+        if !self.has_role(self.admin_of(role), caller) {
+            return Err(PSP22Error::MissingRole);
+        }
+        if self.has_role(role, account) {
+            return Ok(vec![]);
+        }
+        self.roles.insert((role, account), &());
+        Ok(vec![AccessControlEvent::RoleGranted {
+            role,
+            account,
+            sender: caller,
+        }])
+    }
+
+    pub fn revoke_role(
+        &mut self,
+        role: RoleId,
+        account: AccountId,
+        caller: AccountId,
+    ) -> Result<Vec<AccessControlEvent>, PSP22Error> {
+        if !self.has_role(self.admin_of(role), caller) {
+            return Err(PSP22Error::MissingRole);
+        }
+        Ok(self.take_role(role, account, caller))
+    }
+
+    /// Drops `role` from the caller's own account; unlike `revoke_role`, this
+    /// needs no admin permission.
+    pub fn renounce_role(&mut self, role: RoleId, caller: AccountId) -> Vec<AccessControlEvent> {
+        self.take_role(role, caller, caller)
+    }
+
+    fn take_role(
+        &mut self,
+        role: RoleId,
+        account: AccountId,
+        sender: AccountId,
+    ) -> Vec<AccessControlEvent> {
+        if !self.has_role(role, account) {
+            return vec![];
+        }
+        self.roles.remove((role, account));
+        vec![AccessControlEvent::RoleRevoked {
+            role,
+            account,
+            sender,
+        }]
+    }
+}
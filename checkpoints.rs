@@ -0,0 +1,183 @@
+use ink::prelude::vec;
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+use ink::storage::Mapping;
+
+pub type Balance = u128;
+pub type BlockNumber = u32;
+
+/// Events emitted by `Checkpoints`' methods, to be re-emitted by the contract
+/// that embeds it.
+#[derive(Debug)]
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+pub enum VotesEvent {
+    DelegateChanged {
+        delegator: AccountId,
+        from_delegate: Option<AccountId>,
+        to_delegate: AccountId,
+    },
+    DelegateVotesChanged {
+        delegate: AccountId,
+        previous_votes: Balance,
+        new_votes: Balance,
+    },
+}
+
+/// Storage and logic for ERC20Votes-style checkpointed voting power, meant to
+/// be embedded as a field alongside `PSP22Data` in a contract's storage
+/// struct.
+///
+/// Voting power follows an account's *delegate*, not its balance directly:
+/// nobody has voting power until someone delegates to them, and an account
+/// does not vote with its own balance until it explicitly delegates to
+/// itself (the ERC20Votes convention - delegation defaults to nobody, not to
+/// self).
+///
+/// `Checkpoints` does not observe `PSP22Data` on its own - nothing here is
+/// wired to `transfer`/`mint`/`burn` automatically. An embedding contract
+/// (like `Token`) is responsible for calling `move_voting_power` itself
+/// whenever a delegated balance changes.
+#[derive(Default)]
+#[ink::storage_item]
+pub struct Checkpoints {
+    delegates: Mapping<AccountId, AccountId>,
+    checkpoints: Mapping<(AccountId, u32), (BlockNumber, Balance)>,
+    num_checkpoints: Mapping<AccountId, u32>,
+}
+
+impl Checkpoints {
+    /// The account that `account`'s balance currently votes through, if any.
+    pub fn delegates(&self, account: AccountId) -> Option<AccountId> {
+        self.delegates.get(account)
+    }
+
+    /// Current voting power of `account`, i.e. the votes recorded in its most
+    /// recent checkpoint.
+    pub fn get_votes(&self, account: AccountId) -> Balance {
+        let count = self.num_checkpoints.get(account).unwrap_or_default();
+        if count == 0 {
+            return 0;
+        }
+        self.checkpoint_votes(account, count - 1)
+    }
+
+    /// Binary searches `account`'s checkpoint history for the voting power in
+    /// effect at `block`, i.e. the latest checkpoint with `block_number <=
+    /// block`. Returns `0` if `account` never held voting power by then.
+    pub fn get_past_votes(&self, account: AccountId, block: BlockNumber) -> Balance {
+        let count = self.num_checkpoints.get(account).unwrap_or_default();
+        if count == 0 {
+            return 0;
+        }
+
+        let mut low = 0u32;
+        let mut high = count;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (checkpoint_block, _) = self.checkpoints.get((account, mid)).unwrap();
+            if checkpoint_block > block {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        if low == 0 {
+            return 0;
+        }
+        self.checkpoint_votes(account, low - 1)
+    }
+
+    /// Redirects `delegator`'s `balance` from its current delegate (if any) to
+    /// `new_delegate`, emitting `DelegateChanged` plus a `DelegateVotesChanged`
+    /// per delegate whose tally actually moved.
+    pub fn delegate(
+        &mut self,
+        delegator: AccountId,
+        new_delegate: AccountId,
+        balance: Balance,
+        now: BlockNumber,
+    ) -> Vec<VotesEvent> {
+        let old_delegate = self.delegates.get(delegator);
+        if old_delegate == Some(new_delegate) {
+            return vec![];
+        }
+        self.delegates.insert(delegator, &new_delegate);
+
+        let mut events = vec![VotesEvent::DelegateChanged {
+            delegator,
+            from_delegate: old_delegate,
+            to_delegate: new_delegate,
+        }];
+        events.extend(self.move_voting_power(old_delegate, Some(new_delegate), balance, now));
+        events
+    }
+
+    /// Moves `amount` of voting power from `from`'s delegate to `to`'s
+    /// delegate, writing a fresh checkpoint for each affected delegate (or
+    /// overwriting its last one if that was already written in block `now`).
+    /// `from`/`to` being `None` means "no delegate on that side" (e.g. a mint
+    /// has no source, a burn has no destination); no checkpoint is written
+    /// for a `None` side.
+    pub fn move_voting_power(
+        &mut self,
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        amount: Balance,
+        now: BlockNumber,
+    ) -> Vec<VotesEvent> {
+        let mut events = Vec::new();
+        if amount == 0 || from == to {
+            return events;
+        }
+        if let Some(from) = from {
+            events.push(self.write_checkpoint(from, now, |votes| votes - amount));
+        }
+        if let Some(to) = to {
+            events.push(self.write_checkpoint(to, now, |votes| votes + amount));
+        }
+        events
+    }
+
+    fn checkpoint_votes(&self, account: AccountId, index: u32) -> Balance {
+        self.checkpoints
+            .get((account, index))
+            .map(|(_, votes)| votes)
+            .unwrap_or_default()
+    }
+
+    fn write_checkpoint(
+        &mut self,
+        delegate: AccountId,
+        now: BlockNumber,
+        op: impl FnOnce(Balance) -> Balance,
+    ) -> VotesEvent {
+        let count = self.num_checkpoints.get(delegate).unwrap_or_default();
+        let previous_votes = if count == 0 {
+            0
+        } else {
+            self.checkpoint_votes(delegate, count - 1)
+        };
+        let new_votes = op(previous_votes);
+
+        if count > 0 {
+            let (last_block, _) = self.checkpoints.get((delegate, count - 1)).unwrap();
+            if last_block == now {
+                self.checkpoints
+                    .insert((delegate, count - 1), &(now, new_votes));
+                return VotesEvent::DelegateVotesChanged {
+                    delegate,
+                    previous_votes,
+                    new_votes,
+                };
+            }
+        }
+        self.checkpoints
+            .insert((delegate, count), &(now, new_votes));
+        self.num_checkpoints.insert(delegate, &(count + 1));
+        VotesEvent::DelegateVotesChanged {
+            delegate,
+            previous_votes,
+            new_votes,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use ink::env::call::FromAccountId;
+use ink::prelude::{string::String, vec::Vec};
+use ink::primitives::AccountId;
+
+use crate::{PSP22Error, PSP22Metadata, TokenRef, PSP22};
+
+pub type Balance = u128;
+
+/// Thin wrapper around the ink!-generated `TokenRef`, so another contract can
+/// call any standards-compliant PSP22 token at a known address through the
+/// pinned selectors without building up a `TokenRef`/call-builder by hand:
+/// `Psp22Client::at(address).transfer(to, value, data)`.
+pub struct Psp22Client {
+    token: TokenRef,
+}
+
+impl Psp22Client {
+    /// Wraps the PSP22 token deployed at `account`.
+    pub fn at(account: AccountId) -> Self {
+        Self {
+            token: TokenRef::from_account_id(account),
+        }
+    }
+
+    pub fn total_supply(&self) -> Balance {
+        self.token.total_supply()
+    }
+
+    pub fn balance_of(&self, owner: AccountId) -> Balance {
+        self.token.balance_of(owner)
+    }
+
+    pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+        self.token.allowance(owner, spender)
+    }
+
+    pub fn transfer(
+        &mut self,
+        to: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        self.token.transfer(to, value, data)
+    }
+
+    pub fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        self.token.transfer_from(from, to, value, data)
+    }
+
+    pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
+        self.token.approve(spender, value)
+    }
+
+    pub fn increase_allowance(
+        &mut self,
+        spender: AccountId,
+        delta_value: Balance,
+    ) -> Result<(), PSP22Error> {
+        self.token.increase_allowance(spender, delta_value)
+    }
+
+    pub fn decrease_allowance(
+        &mut self,
+        spender: AccountId,
+        delta_value: Balance,
+    ) -> Result<(), PSP22Error> {
+        self.token.decrease_allowance(spender, delta_value)
+    }
+
+    pub fn token_name(&self) -> Option<String> {
+        self.token.token_name()
+    }
+
+    pub fn token_symbol(&self) -> Option<String> {
+        self.token.token_symbol()
+    }
+
+    pub fn token_decimals(&self) -> u8 {
+        self.token.token_decimals()
+    }
+}
@@ -0,0 +1,229 @@
+use ink::prelude::vec;
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+use ink::storage::Mapping;
+
+use crate::errors::PSP22Error;
+
+pub type Balance = u128;
+
+/// Events emitted by `PSP22Data`'s methods, to be re-emitted by the contract
+/// that embeds it.
+#[derive(Debug)]
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+pub enum PSP22Event {
+    Transfer {
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        value: Balance,
+    },
+    Approval {
+        owner: AccountId,
+        spender: AccountId,
+        amount: Balance,
+    },
+}
+
+/// Storage and logic for a PSP22 token, meant to be embedded as a field in a
+/// contract's storage struct. Every method returns the `PSP22Event`s that the
+/// embedding contract is responsible for re-emitting via `self.env().emit_event`.
+///
+/// `PSP22Data` on its own does not know about [`crate::Checkpoints`]: balance
+/// changes here never move voting power. An embedder that wants
+/// ERC20Votes-style checkpoints (as `Token` does) must call
+/// `Checkpoints::move_voting_power` itself alongside `transfer`/`mint`/`burn` -
+/// see `Token::move_delegated_votes` in `lib.rs`.
+#[derive(Default)]
+#[ink::storage_item]
+pub struct PSP22Data {
+    pub total_supply: Balance,
+    pub balances: Mapping<AccountId, Balance>,
+    pub allowances: Mapping<(AccountId, AccountId), Balance>,
+    /// Per-owner replay-protection counter, consumed by `permit`.
+    pub nonces: Mapping<AccountId, u64>,
+}
+
+impl PSP22Data {
+    pub fn new(supply: Balance, creator: AccountId) -> Self {
+        let mut data: PSP22Data = Default::default();
+        if supply != 0 {
+            data.balances.insert(creator, &supply);
+            data.total_supply = supply;
+        }
+        data
+    }
+
+    pub fn total_supply(&self) -> Balance {
+        self.total_supply
+    }
+
+    pub fn balance_of(&self, owner: AccountId) -> Balance {
+        self.balances.get(owner).unwrap_or_default()
+    }
+
+    pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+        self.allowances.get((owner, spender)).unwrap_or_default()
+    }
+
+    /// Current nonce for `owner`, i.e. the value that must appear in their next
+    /// `permit` payload.
+    pub fn nonce(&self, owner: AccountId) -> u64 {
+        self.nonces.get(owner).unwrap_or_default()
+    }
+
+    /// Consumes `owner`'s nonce and records a new `spender` allowance, as the final
+    /// step of a verified `permit` call. Fails if `expected_nonce` no longer
+    /// matches `owner`'s current nonce.
+    pub fn permit_approve(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        expected_nonce: u64,
+    ) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if self.nonce(owner) != expected_nonce {
+            return Err(PSP22Error::NonceMismatch);
+        }
+        self.nonces.insert(owner, &(expected_nonce + 1));
+        self.approve(owner, spender, value)
+    }
+
+    pub fn transfer(
+        &mut self,
+        caller: AccountId,
+        to: AccountId,
+        value: Balance,
+    ) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if caller == to || value == 0 {
+            return Ok(vec![]);
+        }
+        let from_balance = self.balance_of(caller);
+        if from_balance < value {
+            return Err(PSP22Error::InsufficientBalance);
+        }
+        self.balances.insert(caller, &(from_balance - value));
+        let to_balance = self.balance_of(to);
+        self.balances.insert(to, &(to_balance + value));
+        Ok(vec![PSP22Event::Transfer {
+            from: Some(caller),
+            to: Some(to),
+            value,
+        }])
+    }
+
+    pub fn transfer_from(
+        &mut self,
+        spender: AccountId,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+    ) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if spender == from {
+            return self.transfer(from, to, value);
+        }
+        let allowance = self.allowance(from, spender);
+        if allowance < value {
+            return Err(PSP22Error::InsufficientAllowance);
+        }
+        let mut events = self.transfer(from, to, value)?;
+        if value != 0 {
+            self.allowances
+                .insert((from, spender), &(allowance - value));
+            events.push(PSP22Event::Approval {
+                owner: from,
+                spender,
+                amount: allowance - value,
+            });
+        }
+        Ok(events)
+    }
+
+    pub fn approve(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+    ) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if owner == spender {
+            return Ok(vec![]);
+        }
+        self.allowances.insert((owner, spender), &value);
+        Ok(vec![PSP22Event::Approval {
+            owner,
+            spender,
+            amount: value,
+        }])
+    }
+
+    pub fn increase_allowance(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        delta_value: Balance,
+    ) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if owner == spender || delta_value == 0 {
+            return Ok(vec![]);
+        }
+        let allowance = self.allowance(owner, spender);
+        self.allowances
+            .insert((owner, spender), &(allowance + delta_value));
+        Ok(vec![PSP22Event::Approval {
+            owner,
+            spender,
+            amount: allowance + delta_value,
+        }])
+    }
+
+    pub fn decrease_allowance(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        delta_value: Balance,
+    ) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if owner == spender || delta_value == 0 {
+            return Ok(vec![]);
+        }
+        let allowance = self.allowance(owner, spender);
+        if allowance < delta_value {
+            return Err(PSP22Error::InsufficientAllowance);
+        }
+        self.allowances
+            .insert((owner, spender), &(allowance - delta_value));
+        Ok(vec![PSP22Event::Approval {
+            owner,
+            spender,
+            amount: allowance - delta_value,
+        }])
+    }
+
+    pub fn mint(&mut self, to: AccountId, value: Balance) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if value == 0 {
+            return Ok(vec![]);
+        }
+        let to_balance = self.balance_of(to);
+        self.balances.insert(to, &(to_balance + value));
+        self.total_supply += value;
+        Ok(vec![PSP22Event::Transfer {
+            from: None,
+            to: Some(to),
+            value,
+        }])
+    }
+
+    pub fn burn(&mut self, from: AccountId, value: Balance) -> Result<Vec<PSP22Event>, PSP22Error> {
+        if value == 0 {
+            return Ok(vec![]);
+        }
+        let from_balance = self.balance_of(from);
+        if from_balance < value {
+            return Err(PSP22Error::InsufficientBalance);
+        }
+        self.balances.insert(from, &(from_balance - value));
+        self.total_supply -= value;
+        Ok(vec![PSP22Event::Transfer {
+            from: Some(from),
+            to: None,
+            value,
+        }])
+    }
+}
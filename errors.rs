@@ -0,0 +1,38 @@
+use ink::prelude::string::String;
+
+/// Errors that can occur while calling this contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22Error {
+    /// Custom error type for implementation-based errors.
+    Custom(String),
+    /// Returned when an account does not have enough tokens to complete the operation.
+    InsufficientBalance,
+    /// Returned when there is not enough allowance to complete the operation.
+    InsufficientAllowance,
+    /// Returned when recipient's address is zero.
+    ZeroRecipientAddress,
+    /// Returned when sender's address is zero.
+    ZeroSenderAddress,
+    /// Returned when a safe transfer check fails (see ERC-223 token standard).
+    SafeTransferCheckFailed(String),
+    /// Returned by `permit` when `block_number() > deadline`.
+    PermitExpired,
+    /// Returned by `permit` when the supplied signature does not recover to `owner`
+    /// for the expected nonce (a stale or forged signature).
+    InvalidSignature,
+    /// Returned by `PSP22Data::permit_approve` when `expected_nonce` differs
+    /// from `owner`'s current nonce. `permit` signs over the current nonce, so
+    /// a signature over a stale nonce fails there with `InvalidSignature`.
+    NonceMismatch,
+    /// Returned when the caller lacks the role required for the operation
+    /// (e.g. `MINTER`/`BURNER` on `mint`/`burn`, or a role's admin role on
+    /// `grant_role`/`revoke_role`).
+    MissingRole,
+    /// Returned by transfer/approval/mint messages while the contract is
+    /// paused.
+    Paused,
+    /// Returned by `mint` when minting would push `total_supply()` above the
+    /// configured `cap()`.
+    CapExceeded,
+}
@@ -3,28 +3,51 @@
 
 pub use self::token::TokenRef;
 
+mod access;
+mod checkpoints;
+mod cross_contract;
 mod data;
 mod errors;
 mod testing;
 mod traits;
 
+pub use access::{AccessControl, AccessControlEvent, RoleId, ADMIN, BURNER, MINTER};
+pub use checkpoints::{Checkpoints, VotesEvent};
+pub use cross_contract::Psp22Client;
 pub use data::{PSP22Data, PSP22Event};
 pub use errors::PSP22Error;
-pub use traits::{PSP22Burnable, PSP22Metadata, PSP22Mintable, PSP22};
+pub use traits::{PSP22Burnable, PSP22Metadata, PSP22Mintable, PSP22Permit, PSP22Votes, PSP22};
 
 #[ink::contract]
 mod token {
-    use crate::{PSP22Data, PSP22Error, PSP22Event, PSP22Metadata, PSP22};
+    use crate::{
+        AccessControl, AccessControlEvent, Checkpoints, PSP22Burnable, PSP22Data, PSP22Error,
+        PSP22Event, PSP22Metadata, PSP22Mintable, PSP22Permit, PSP22Votes, RoleId, VotesEvent,
+        ADMIN, BURNER, MINTER, PSP22,
+    };
+    use ink::env::hash::{Blake2x256, HashOutput};
     use ink::prelude::{string::String, vec::Vec};
-    use crate::PSP22Error::Custom;
+    use scale::Encode;
+
+    /// Bumped whenever the `permit` payload layout changes, so old signatures
+    /// can never be replayed against a new encoding.
+    const PERMIT_PAYLOAD_VERSION: u8 = 1;
 
     #[ink(storage)]
     pub struct Token {
         data: PSP22Data,
+        checkpoints: Checkpoints,
+        access: AccessControl,
         name: Option<String>,
         symbol: Option<String>,
-        bond: AccountId,
         decimals: u8,
+        paused: bool,
+        cap: Option<u128>,
+        /// Caller-supplied chain identifier, folded into every `permit`
+        /// domain tag so a signature collected for one chain (e.g. a
+        /// parachain id, or a testnet/mainnet split) can't be replayed on
+        /// another deployment of this same contract code.
+        chain_id: u32,
     }
 
     impl Token {
@@ -32,14 +55,20 @@ mod token {
         pub fn new(
             name: Option<String>,
             symbol: Option<String>,
-            bond: AccountId,
+            admin: AccountId,
+            cap: Option<u128>,
+            chain_id: u32,
         ) -> Self {
             Self {
                 data: PSP22Data::default(),
+                checkpoints: Checkpoints::default(),
+                access: AccessControl::new(admin),
                 name,
                 symbol,
-                bond,
                 decimals: 12,
+                paused: false,
+                cap,
+                chain_id,
             }
         }
 
@@ -62,25 +91,167 @@ mod token {
             }
         }
 
-        #[ink(message)]
-        pub fn mint(&mut self, to: AccountId, value: u128) -> Result<(), PSP22Error> {
-            self.only_bond()?;
-            self.data.mint(to, value)?;
+        fn emit_vote_events(&self, events: Vec<VotesEvent>) {
+            for event in events {
+                match event {
+                    VotesEvent::DelegateChanged {
+                        delegator,
+                        from_delegate,
+                        to_delegate,
+                    } => self.env().emit_event(DelegateChanged {
+                        delegator,
+                        from_delegate,
+                        to_delegate,
+                    }),
+                    VotesEvent::DelegateVotesChanged {
+                        delegate,
+                        previous_votes,
+                        new_votes,
+                    } => self.env().emit_event(DelegateVotesChanged {
+                        delegate,
+                        previous_votes,
+                        new_votes,
+                    }),
+                }
+            }
+        }
+
+        /// Moves `value` of voting power from `from`'s delegate to `to`'s
+        /// delegate, so a balance change takes effect on voting power
+        /// immediately. `from`/`to` of `None` means "no such account" (mint
+        /// has no source, burn has no destination).
+        fn move_delegated_votes(
+            &mut self,
+            from: Option<AccountId>,
+            to: Option<AccountId>,
+            value: u128,
+        ) {
+            let from_delegate = from.and_then(|account| self.checkpoints.delegates(account));
+            let to_delegate = to.and_then(|account| self.checkpoints.delegates(account));
+            let events = self.checkpoints.move_voting_power(
+                from_delegate,
+                to_delegate,
+                value,
+                self.env().block_number(),
+            );
+            self.emit_vote_events(events);
+        }
+
+        fn ensure_role(&self, role: RoleId) -> Result<(), PSP22Error> {
+            if !self.access.has_role(role, self.env().caller()) {
+                return Err(PSP22Error::MissingRole);
+            }
+            Ok(())
+        }
+
+        fn ensure_not_paused(&self) -> Result<(), PSP22Error> {
+            if self.paused {
+                return Err(PSP22Error::Paused);
+            }
             Ok(())
         }
 
         #[ink(message)]
-        pub fn burn(&mut self, from: AccountId, value: u128) -> Result<(), PSP22Error> {
-            self.only_bond()?;
-            self.data.burn(from, value)?;
+        pub fn paused(&self) -> bool {
+            self.paused
+        }
+
+        #[ink(message)]
+        pub fn cap(&self) -> Option<u128> {
+            self.cap
+        }
+
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), PSP22Error> {
+            self.ensure_role(ADMIN)?;
+            self.paused = true;
+            self.env().emit_event(Paused {});
             Ok(())
         }
 
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<(), PSP22Error> {
+            self.ensure_role(ADMIN)?;
+            self.paused = false;
+            self.env().emit_event(Unpaused {});
+            Ok(())
+        }
 
-        fn only_bond(&self) -> Result<(), PSP22Error> {
-            if self.env().caller() != self.bond {
-                return Err(Custom("Not Permission".parse().unwrap()));
+        fn emit_access_events(&self, events: Vec<AccessControlEvent>) {
+            for event in events {
+                match event {
+                    AccessControlEvent::RoleGranted {
+                        role,
+                        account,
+                        sender,
+                    } => self.env().emit_event(RoleGranted {
+                        role,
+                        account,
+                        sender,
+                    }),
+                    AccessControlEvent::RoleRevoked {
+                        role,
+                        account,
+                        sender,
+                    } => self.env().emit_event(RoleRevoked {
+                        role,
+                        account,
+                        sender,
+                    }),
+                    AccessControlEvent::RoleAdminChanged {
+                        role,
+                        previous_admin_role,
+                        new_admin_role,
+                    } => self.env().emit_event(RoleAdminChanged {
+                        role,
+                        previous_admin_role,
+                        new_admin_role,
+                    }),
+                }
             }
+        }
+
+        #[ink(message)]
+        pub fn has_role(&self, role: RoleId, account: AccountId) -> bool {
+            self.access.has_role(role, account)
+        }
+
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: RoleId, account: AccountId) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let events = self.access.grant_role(role, account, caller)?;
+            self.emit_access_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: RoleId, account: AccountId) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let events = self.access.revoke_role(role, account, caller)?;
+            self.emit_access_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn renounce_role(&mut self, role: RoleId) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let events = self.access.renounce_role(role, caller);
+            self.emit_access_events(events);
+            Ok(())
+        }
+
+        /// Reassigns the role allowed to grant/revoke `role` to
+        /// `new_admin_role`. Only an existing holder of `role`'s current
+        /// admin role may do this.
+        #[ink(message)]
+        pub fn set_role_admin(
+            &mut self,
+            role: RoleId,
+            new_admin_role: RoleId,
+        ) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let events = self.access.set_role_admin(role, new_admin_role, caller)?;
+            self.emit_access_events(events);
             Ok(())
         }
     }
@@ -103,35 +274,88 @@ mod token {
         value: u128,
     }
 
+    #[ink(event)]
+    pub struct DelegateChanged {
+        #[ink(topic)]
+        delegator: AccountId,
+        #[ink(topic)]
+        from_delegate: Option<AccountId>,
+        #[ink(topic)]
+        to_delegate: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct DelegateVotesChanged {
+        #[ink(topic)]
+        delegate: AccountId,
+        previous_votes: u128,
+        new_votes: u128,
+    }
+
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        role: RoleId,
+        #[ink(topic)]
+        account: AccountId,
+        sender: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        role: RoleId,
+        #[ink(topic)]
+        account: AccountId,
+        sender: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct RoleAdminChanged {
+        #[ink(topic)]
+        role: RoleId,
+        previous_admin_role: RoleId,
+        new_admin_role: RoleId,
+    }
+
+    #[ink(event)]
+    pub struct Paused {}
+
+    #[ink(event)]
+    pub struct Unpaused {}
+
     impl PSP22 for Token {
-        #[ink(message)]
+        #[ink(message, selector = 0x162df8c2)]
         fn total_supply(&self) -> u128 {
             self.data.total_supply()
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x6568382f)]
         fn balance_of(&self, owner: AccountId) -> u128 {
             self.data.balance_of(owner)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x4d47d921)]
         fn allowance(&self, owner: AccountId, spender: AccountId) -> u128 {
             self.data.allowance(owner, spender)
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0xdb20f9f5)]
         fn transfer(
             &mut self,
             to: AccountId,
             value: u128,
             _data: Vec<u8>,
         ) -> Result<(), PSP22Error> {
-            let events = self.data.transfer(self.env().caller(), to, value)?;
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let events = self.data.transfer(caller, to, value)?;
             self.emit_events(events);
+            self.move_delegated_votes(Some(caller), Some(to), value);
             Ok(())
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x54b3c76e)]
         fn transfer_from(
             &mut self,
             from: AccountId,
@@ -139,26 +363,30 @@ mod token {
             value: u128,
             _data: Vec<u8>,
         ) -> Result<(), PSP22Error> {
+            self.ensure_not_paused()?;
             let events = self
                 .data
                 .transfer_from(self.env().caller(), from, to, value)?;
             self.emit_events(events);
+            self.move_delegated_votes(Some(from), Some(to), value);
             Ok(())
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0xb20f1bbd)]
         fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error> {
+            self.ensure_not_paused()?;
             let events = self.data.approve(self.env().caller(), spender, value)?;
             self.emit_events(events);
             Ok(())
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0x96d6b57a)]
         fn increase_allowance(
             &mut self,
             spender: AccountId,
             delta_value: u128,
         ) -> Result<(), PSP22Error> {
+            self.ensure_not_paused()?;
             let events = self
                 .data
                 .increase_allowance(self.env().caller(), spender, delta_value)?;
@@ -166,12 +394,13 @@ mod token {
             Ok(())
         }
 
-        #[ink(message)]
+        #[ink(message, selector = 0xfecb57d5)]
         fn decrease_allowance(
             &mut self,
             spender: AccountId,
             delta_value: u128,
         ) -> Result<(), PSP22Error> {
+            self.ensure_not_paused()?;
             let events = self
                 .data
                 .decrease_allowance(self.env().caller(), spender, delta_value)?;
@@ -181,23 +410,402 @@ mod token {
     }
 
     impl PSP22Metadata for Token {
-        #[ink(message)]
+        #[ink(message, selector = 0x3d261bd4)]
         fn token_name(&self) -> Option<String> {
             self.name.clone()
         }
-        #[ink(message)]
+        #[ink(message, selector = 0x34205be5)]
         fn token_symbol(&self) -> Option<String> {
             self.symbol.clone()
         }
-        #[ink(message)]
+        #[ink(message, selector = 0x7271b782)]
         fn token_decimals(&self) -> u8 {
             self.decimals
         }
     }
 
-    // // (7)
-    // #[cfg(test)]
-    // mod tests {
-    //     crate::tests!(Token, (|supply| Token::new(None, None, 0)));
-    // }
+    impl PSP22Mintable for Token {
+        #[ink(message, selector = 0xfc3c75d4)]
+        fn mint(&mut self, to: AccountId, value: u128) -> Result<(), PSP22Error> {
+            self.ensure_not_paused()?;
+            self.ensure_role(MINTER)?;
+            if let Some(cap) = self.cap {
+                let new_supply = self
+                    .data
+                    .total_supply()
+                    .checked_add(value)
+                    .ok_or(PSP22Error::CapExceeded)?;
+                if new_supply > cap {
+                    return Err(PSP22Error::CapExceeded);
+                }
+            }
+            self.data.mint(to, value)?;
+            self.move_delegated_votes(None, Some(to), value);
+            Ok(())
+        }
+    }
+
+    impl PSP22Burnable for Token {
+        #[ink(message, selector = 0x7a9da510)]
+        fn burn(&mut self, from: AccountId, value: u128) -> Result<(), PSP22Error> {
+            self.ensure_role(BURNER)?;
+            self.data.burn(from, value)?;
+            self.move_delegated_votes(Some(from), None, value);
+            Ok(())
+        }
+    }
+
+    impl PSP22Permit for Token {
+        #[ink(message)]
+        fn permit(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            value: u128,
+            deadline: BlockNumber,
+            signature: [u8; 64],
+        ) -> Result<(), PSP22Error> {
+            self.ensure_not_paused()?;
+
+            if self.env().block_number() > deadline {
+                return Err(PSP22Error::PermitExpired);
+            }
+
+            let nonce = self.data.nonce(owner);
+            let message_hash = self.permit_hash(owner, spender, value, nonce, deadline);
+            let owner_pub_key: [u8; 32] = *owner.as_ref();
+            ink::env::sr25519_verify(&signature, &message_hash, &owner_pub_key)
+                .map_err(|_| PSP22Error::InvalidSignature)?;
+
+            let events = self.data.permit_approve(owner, spender, value, nonce)?;
+            self.emit_events(events);
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn nonce(&self, owner: AccountId) -> u64 {
+            self.data.nonce(owner)
+        }
+    }
+
+    impl PSP22Votes for Token {
+        #[ink(message)]
+        fn get_votes(&self, account: AccountId) -> u128 {
+            self.checkpoints.get_votes(account)
+        }
+
+        #[ink(message)]
+        fn get_past_votes(&self, account: AccountId, block: BlockNumber) -> u128 {
+            self.checkpoints.get_past_votes(account, block)
+        }
+
+        #[ink(message)]
+        fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error> {
+            let delegator = self.env().caller();
+            let balance = self.data.balance_of(delegator);
+            let events =
+                self.checkpoints
+                    .delegate(delegator, delegatee, balance, self.env().block_number());
+            self.emit_vote_events(events);
+            Ok(())
+        }
+    }
+
+    impl Token {
+        /// Builds the `Blake2x256` digest that `permit` signatures are taken
+        /// over: a domain tag (`chain_id` + this contract's `AccountId` +
+        /// a payload-version byte) followed by the SCALE encoding of the
+        /// `(owner, spender, value, nonce, deadline)` tuple. Folding in
+        /// `chain_id` keeps a signature collected for one chain from being
+        /// replayed against another deployment of this same contract code.
+        fn permit_hash(
+            &self,
+            owner: AccountId,
+            spender: AccountId,
+            value: u128,
+            nonce: u64,
+            deadline: BlockNumber,
+        ) -> [u8; 32] {
+            let mut payload = self.chain_id.encode();
+            payload.extend_from_slice(&self.env().account_id().encode());
+            payload.push(PERMIT_PAYLOAD_VERSION);
+            payload.extend_from_slice(&(owner, spender, value, nonce, deadline).encode());
+
+            let mut hash = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Blake2x256>(&payload, &mut hash);
+            hash
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        crate::tests!(
+            Token,
+            (|supply| {
+                let admin = default_accounts::<DefaultEnvironment>().alice;
+                let mut token = Token::new(None, None, admin, None, 0);
+                token
+                    .grant_role(MINTER, admin)
+                    .expect("admin grants itself MINTER");
+                token.mint(admin, supply).expect("mint succeeds");
+                token
+            })
+        );
+
+        #[ink::test]
+        fn mint_without_minter_role_is_rejected() {
+            let mut token = Token::new(None, None, alice(), None, 0);
+            ink::env::test::set_caller::<DefaultEnvironment>(bob());
+            assert_eq!(token.mint(bob(), 100), Err(PSP22Error::MissingRole));
+        }
+
+        #[ink::test]
+        fn mint_beyond_cap_is_rejected() {
+            let mut token = Token::new(None, None, alice(), Some(100), 0);
+            ink::env::test::set_caller::<DefaultEnvironment>(alice());
+            token.grant_role(MINTER, alice()).unwrap();
+
+            assert_eq!(token.mint(alice(), 101), Err(PSP22Error::CapExceeded));
+            assert_eq!(token.mint(alice(), 100), Ok(()));
+        }
+
+        #[ink::test]
+        fn paused_token_rejects_transfer() {
+            let mut token = Token::new(None, None, alice(), None, 0);
+            ink::env::test::set_caller::<DefaultEnvironment>(alice());
+            token.grant_role(MINTER, alice()).unwrap();
+            token.mint(alice(), 100).unwrap();
+
+            token.pause().unwrap();
+            assert_eq!(
+                token.transfer(bob(), 10, Vec::new()),
+                Err(PSP22Error::Paused)
+            );
+        }
+
+        #[ink::test]
+        fn get_past_votes_is_zero_before_the_delegating_checkpoint() {
+            let mut token = Token::new(None, None, alice(), None, 0);
+            ink::env::test::set_caller::<DefaultEnvironment>(alice());
+            token.grant_role(MINTER, alice()).unwrap();
+            token.mint(alice(), 100).unwrap();
+
+            let before_delegation = ink::env::block_number::<DefaultEnvironment>();
+            ink::env::test::advance_block::<DefaultEnvironment>();
+            token.delegate(alice()).unwrap();
+
+            assert_eq!(token.get_past_votes(alice(), before_delegation), 0);
+            assert_eq!(token.get_votes(alice()), 100);
+        }
+    }
+}
+
+/// A second, independent contract used only to exercise `Psp22Client`: it
+/// never imports `Token` or any of its types, only `AccountId`s and the
+/// pinned PSP22 selectors reached through `Psp22Client`, so the same calls
+/// reach any token that pins those selectors, not only ones built from this
+/// crate.
+#[cfg(all(test, feature = "e2e-tests"))]
+#[ink::contract]
+mod relayer {
+    use crate::Psp22Client;
+    use ink::prelude::vec::Vec;
+
+    #[ink(storage)]
+    pub struct Relayer {}
+
+    impl Relayer {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        #[ink(message)]
+        pub fn relay_transfer(
+            &mut self,
+            token: AccountId,
+            to: AccountId,
+            value: u128,
+        ) -> Result<(), crate::PSP22Error> {
+            Psp22Client::at(token).transfer(to, value, Vec::new())
+        }
+
+        #[ink(message)]
+        pub fn relay_balance_of(&self, token: AccountId, owner: AccountId) -> u128 {
+            Psp22Client::at(token).balance_of(owner)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "e2e-tests"))]
+mod e2e_tests {
+    use crate::relayer::RelayerRef;
+    use crate::token::TokenRef;
+    use crate::{PSP22Mintable, PSP22Permit, MINTER, PSP22};
+    use ink_e2e::{ContractsBackend, E2EBackend};
+    use scale::Encode;
+
+    type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    /// Deploys `Token` and `Relayer` side by side, then drives every
+    /// `Relayer` message - which only ever goes through `Psp22Client`'s
+    /// pinned selectors - to confirm they reach `Token` exactly as direct
+    /// `TokenRef` calls do.
+    #[ink_e2e::test]
+    async fn relayer_moves_tokens_through_pinned_selectors<Client: E2EBackend>(
+        mut client: Client,
+    ) -> E2EResult<()> {
+        let alice = client.alice_account_id();
+        let bob = client.bob_account_id();
+
+        let mut token_constructor = TokenRef::new(None, None, alice, None, 0);
+        let mut token = client
+            .instantiate("token", &ink_e2e::alice(), &mut token_constructor)
+            .submit()
+            .await
+            .expect("token instantiate failed");
+        let token_account = token.account_id;
+        let mut token_call = token.call_builder::<TokenRef>();
+
+        let grant_minter = token_call.grant_role(MINTER, alice);
+        client
+            .call(&ink_e2e::alice(), &grant_minter)
+            .submit()
+            .await
+            .expect("grant_role failed");
+
+        let mint = token_call.mint(alice, 1_000);
+        client
+            .call(&ink_e2e::alice(), &mint)
+            .submit()
+            .await
+            .expect("mint failed");
+
+        let mut relayer_constructor = RelayerRef::new();
+        let relayer_instance = client
+            .instantiate("relayer", &ink_e2e::alice(), &mut relayer_constructor)
+            .submit()
+            .await
+            .expect("relayer instantiate failed");
+        let relayer_account = relayer_instance.account_id;
+        let mut relayer = relayer_instance.call_builder::<RelayerRef>();
+
+        // `relay_transfer` moves tokens out of the relayer's own balance (the
+        // relayer is `transfer`'s caller from `Token`'s point of view), so it
+        // has to hold them first.
+        let fund_relayer = token_call.transfer(relayer_account, 100, Vec::new());
+        client
+            .call(&ink_e2e::alice(), &fund_relayer)
+            .submit()
+            .await
+            .expect("funding the relayer failed");
+
+        let relay_transfer = relayer.relay_transfer(token_account, bob, 100);
+        client
+            .call(&ink_e2e::alice(), &relay_transfer)
+            .submit()
+            .await
+            .expect("relayed transfer failed");
+
+        let relay_balance_of = relayer.relay_balance_of(token_account, bob);
+        let balance = client
+            .call(&ink_e2e::alice(), &relay_balance_of)
+            .dry_run()
+            .await?
+            .return_value();
+        assert_eq!(balance, 100);
+
+        Ok(())
+    }
+
+    /// A `permit` signed by `alice` grants `bob` an allowance without alice
+    /// ever submitting a transaction herself; replaying the same signature
+    /// afterwards is rejected because it reuses an already-consumed nonce.
+    #[ink_e2e::test]
+    async fn permit_allows_gasless_approval_then_rejects_replay<Client: E2EBackend>(
+        mut client: Client,
+    ) -> E2EResult<()> {
+        let alice = client.alice_account_id();
+        let bob = client.bob_account_id();
+
+        let mut token_constructor = TokenRef::new(None, None, alice, None, 0);
+        let token = client
+            .instantiate("token", &ink_e2e::alice(), &mut token_constructor)
+            .submit()
+            .await
+            .expect("token instantiate failed");
+        let token_account = token.account_id;
+        let mut token_call = token.call_builder::<TokenRef>();
+
+        let nonce = client
+            .call(&ink_e2e::alice(), &token_call.nonce(alice))
+            .dry_run()
+            .await?
+            .return_value();
+        let deadline = u32::MAX;
+        let value = 500u128;
+
+        // Reconstruct `Token::permit_hash`'s domain tag by hand: this is the
+        // exact message an off-chain relayer would have alice sign.
+        let mut payload = 0u32.encode();
+        payload.extend_from_slice(&token_account.encode());
+        payload.push(1u8);
+        payload.extend_from_slice(&(alice, bob, value, nonce, deadline).encode());
+        let mut message_hash = [0u8; 32];
+        ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&payload, &mut message_hash);
+        let signature = ink_e2e::alice().sign(&message_hash).0;
+
+        let permit = token_call.permit(alice, bob, value, deadline, signature);
+        client
+            .call(&ink_e2e::bob(), &permit)
+            .submit()
+            .await
+            .expect("permit failed");
+
+        let allowance = client
+            .call(&ink_e2e::alice(), &token_call.allowance(alice, bob))
+            .dry_run()
+            .await?
+            .return_value();
+        assert_eq!(allowance, value);
+
+        let replay = token_call.permit(alice, bob, value, deadline, signature);
+        let replay_result = client
+            .call(&ink_e2e::bob(), &replay)
+            .dry_run()
+            .await?
+            .return_value();
+        assert_eq!(replay_result, Err(crate::PSP22Error::NonceMismatch));
+
+        Ok(())
+    }
+
+    /// `permit` checks the deadline before it checks the signature, so an
+    /// expired permit is rejected even with a signature that was never
+    /// produced correctly.
+    #[ink_e2e::test]
+    async fn expired_permit_is_rejected<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let alice = client.alice_account_id();
+        let bob = client.bob_account_id();
+
+        let mut token_constructor = TokenRef::new(None, None, alice, None, 0);
+        let token = client
+            .instantiate("token", &ink_e2e::alice(), &mut token_constructor)
+            .submit()
+            .await
+            .expect("token instantiate failed");
+        let token_call = token.call_builder::<TokenRef>();
+
+        let permit = token_call.permit(alice, bob, 500, 0, [0u8; 64]);
+        let result = client
+            .call(&ink_e2e::alice(), &permit)
+            .dry_run()
+            .await?
+            .return_value();
+        assert_eq!(result, Err(crate::PSP22Error::PermitExpired));
+
+        Ok(())
+    }
 }
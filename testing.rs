@@ -0,0 +1,87 @@
+/// Generates a generic PSP22 test-suite against any contract that implements
+/// the `PSP22` trait, so every concrete token only has to provide a
+/// constructor. Usage:
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     crate::tests!(Token, (|supply| {
+///         let admin = alice();
+///         let mut token = Token::new(None, None, admin, None, 0);
+///         token.grant_role(MINTER, admin).expect("admin grants itself MINTER");
+///         token.mint(admin, supply).expect("mint succeeds");
+///         token
+///     }));
+/// }
+/// ```
+#[macro_export]
+macro_rules! tests {
+    ($contract:ident, $constructor:expr) => {
+        use ink::env::test::default_accounts;
+        use ink::env::DefaultEnvironment;
+        use ink::prelude::vec;
+        use $crate::{PSP22Error, PSP22};
+
+        fn alice() -> ink::primitives::AccountId {
+            default_accounts::<DefaultEnvironment>().alice
+        }
+
+        fn bob() -> ink::primitives::AccountId {
+            default_accounts::<DefaultEnvironment>().bob
+        }
+
+        fn set_caller(caller: ink::primitives::AccountId) {
+            ink::env::test::set_caller::<DefaultEnvironment>(caller);
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let token = ($constructor)(100);
+            assert_eq!(token.total_supply(), 100);
+            assert_eq!(token.balance_of(alice()), 100);
+        }
+
+        #[ink::test]
+        fn transfer_works() {
+            let mut token = ($constructor)(100);
+            set_caller(alice());
+            assert_eq!(token.transfer(bob(), 40, vec![]), Ok(()));
+            assert_eq!(token.balance_of(alice()), 60);
+            assert_eq!(token.balance_of(bob()), 40);
+        }
+
+        #[ink::test]
+        fn transfer_insufficient_balance_fails() {
+            let mut token = ($constructor)(100);
+            set_caller(alice());
+            assert_eq!(
+                token.transfer(bob(), 101, vec![]),
+                Err(PSP22Error::InsufficientBalance)
+            );
+        }
+
+        #[ink::test]
+        fn approve_and_transfer_from_works() {
+            let mut token = ($constructor)(100);
+            set_caller(alice());
+            assert_eq!(token.approve(bob(), 40), Ok(()));
+            assert_eq!(token.allowance(alice(), bob()), 40);
+            set_caller(bob());
+            assert_eq!(token.transfer_from(alice(), bob(), 40, vec![]), Ok(()));
+            assert_eq!(token.allowance(alice(), bob()), 0);
+            assert_eq!(token.balance_of(bob()), 40);
+        }
+
+        #[ink::test]
+        fn transfer_from_insufficient_allowance_fails() {
+            let mut token = ($constructor)(100);
+            set_caller(alice());
+            assert_eq!(token.approve(bob(), 10), Ok(()));
+            set_caller(bob());
+            assert_eq!(
+                token.transfer_from(alice(), bob(), 40, vec![]),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+        }
+    };
+}
@@ -0,0 +1,136 @@
+use ink::prelude::{string::String, vec::Vec};
+use ink::primitives::AccountId;
+
+use crate::errors::PSP22Error;
+
+pub type Balance = u128;
+pub type BlockNumber = u32;
+
+/// The standard PSP22 token interface (the Polkadot/Substrate analogue of
+/// ERC20), see <https://github.com/w3f/PSPs/blob/master/PSPs/psp-22.md>.
+#[ink::trait_definition]
+pub trait PSP22 {
+    /// Returns the total token supply.
+    #[ink(message)]
+    fn total_supply(&self) -> Balance;
+
+    /// Returns the account balance for the specified `owner`.
+    #[ink(message)]
+    fn balance_of(&self, owner: AccountId) -> Balance;
+
+    /// Returns the amount which `spender` is still allowed to withdraw from `owner`.
+    #[ink(message)]
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance;
+
+    /// Transfers `value` tokens from the caller's account to account `to`.
+    #[ink(message)]
+    fn transfer(&mut self, to: AccountId, value: Balance, data: Vec<u8>) -> Result<(), PSP22Error>;
+
+    /// Transfers `value` tokens on behalf of `from` to the account `to`.
+    #[ink(message)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Allows `spender` to withdraw up to `value` tokens from the caller's account.
+    #[ink(message)]
+    fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error>;
+
+    /// Increases the allowance granted to `spender` by the caller by `delta_value`.
+    #[ink(message)]
+    fn increase_allowance(
+        &mut self,
+        spender: AccountId,
+        delta_value: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Decreases the allowance granted to `spender` by the caller by `delta_value`.
+    #[ink(message)]
+    fn decrease_allowance(
+        &mut self,
+        spender: AccountId,
+        delta_value: Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// Optional PSP22 metadata extension.
+#[ink::trait_definition]
+pub trait PSP22Metadata {
+    /// Returns the token name.
+    #[ink(message)]
+    fn token_name(&self) -> Option<String>;
+
+    /// Returns the token symbol.
+    #[ink(message)]
+    fn token_symbol(&self) -> Option<String>;
+
+    /// Returns the token decimals.
+    #[ink(message)]
+    fn token_decimals(&self) -> u8;
+}
+
+/// Optional PSP22 extension allowing an `owner` to authorize a `spender` via an
+/// off-chain signature instead of an on-chain `approve` call, so a relayer can
+/// submit the transaction and pay the fee (EIP-2612 style "gasless approvals").
+#[ink::trait_definition]
+pub trait PSP22Permit {
+    /// Sets `spender`'s allowance over `owner`'s tokens to `value`, as authorized
+    /// by `signature` over `owner`'s current nonce. Fails once `block_number()`
+    /// exceeds `deadline`, or if `signature` does not verify.
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: BlockNumber,
+        signature: [u8; 64],
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the current nonce for `owner`, i.e. the value that must be used
+    /// when building their next `permit` payload.
+    #[ink(message)]
+    fn nonce(&self, owner: AccountId) -> u64;
+}
+
+/// Optional PSP22 extension providing ERC20Votes-style checkpointed voting
+/// power, for building on-chain governance without re-deriving balance
+/// history from raw `Transfer` events.
+#[ink::trait_definition]
+pub trait PSP22Votes {
+    /// Current voting power of `account` (the balance of whoever delegates to
+    /// it, summed across all delegators).
+    #[ink(message)]
+    fn get_votes(&self, account: AccountId) -> Balance;
+
+    /// Voting power `account` held at the end of `block`, found via binary
+    /// search over its checkpoint history.
+    #[ink(message)]
+    fn get_past_votes(&self, account: AccountId, block: BlockNumber) -> Balance;
+
+    /// Redirects the caller's voting power to `delegatee`. Call with your own
+    /// `AccountId` to start voting with your own balance - delegation
+    /// defaults to nobody, matching ERC20Votes.
+    #[ink(message)]
+    fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error>;
+}
+
+/// Optional PSP22 extension for contracts that allow minting new tokens.
+#[ink::trait_definition]
+pub trait PSP22Mintable {
+    /// Mints `value` tokens to the account `to`.
+    #[ink(message)]
+    fn mint(&mut self, to: AccountId, value: Balance) -> Result<(), PSP22Error>;
+}
+
+/// Optional PSP22 extension for contracts that allow burning tokens.
+#[ink::trait_definition]
+pub trait PSP22Burnable {
+    /// Burns `value` tokens from the account `from`.
+    #[ink(message)]
+    fn burn(&mut self, from: AccountId, value: Balance) -> Result<(), PSP22Error>;
+}